@@ -283,3 +283,25 @@ pub struct PeImportBinary {
     pub name: RVA,
     pub first_thunk: RVA,
 }
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PeSectionHeader {
+    pub name: [u8; 8],
+    pub virtual_size: u32,
+    pub virtual_address: RVA,
+    pub size_of_raw_data: u32,
+    pub pointer_to_raw_data: u32,
+    pub pointer_to_relocations: u32,
+    pub pointer_to_linenumbers: u32,
+    pub number_of_relocations: u16,
+    pub number_of_linenumbers: u16,
+    pub characteristics: SectionCharacteristics,
+}
+
+impl PeSectionHeader {
+    pub fn get_name(&self) -> String {
+        let len = self.name.iter().position(|&x| x == 0).unwrap_or(self.name.len());
+        String::from_utf8_lossy(&self.name[..len]).to_string()
+    }
+}